@@ -4,20 +4,83 @@ use halo2curves::{
     pairing::{Engine, MultiMillerLoop},
     FieldExt,
 };
-use rand_core::OsRng;
 
 pub(crate) mod prover;
 pub(crate) mod verifier;
+pub mod wasm;
 
 use std::{collections::BTreeMap, io};
 
 use crate::{
-    arithmetic::{best_multiexp, kate_division},
-    helpers::SerdePrimeField,
+    arithmetic::{best_fft, best_multiexp, kate_division},
+    helpers::{SerdeCurveAffine, SerdePrimeField},
     poly::{kzg::commitment::ParamsKZG, EvaluationDomain},
     SerdeFormat,
 };
 
+fn write_u32<W: io::Write>(writer: &mut W, value: u32) -> io::Result<()> {
+    writer.write_all(&value.to_le_bytes())
+}
+
+fn read_u32<R: io::Read>(reader: &mut R) -> io::Result<u32> {
+    let mut bytes = [0u8; 4];
+    reader.read_exact(&mut bytes)?;
+    Ok(u32::from_le_bytes(bytes))
+}
+
+fn write_curve_points<W: io::Write, C: SerdeCurveAffine>(
+    writer: &mut W,
+    points: &[C],
+    format: SerdeFormat,
+) -> io::Result<()> {
+    write_u32(writer, points.len() as u32)?;
+    for point in points {
+        point.write(writer, format)?;
+    }
+    Ok(())
+}
+
+fn read_curve_points<R: io::Read, C: SerdeCurveAffine>(
+    reader: &mut R,
+    format: SerdeFormat,
+) -> io::Result<Vec<C>> {
+    let len = read_u32(reader)? as usize;
+    (0..len).map(|_| C::read(reader, format)).collect()
+}
+
+fn write_field_elements<W: io::Write, F: SerdePrimeField>(
+    writer: &mut W,
+    elements: &[F],
+    format: SerdeFormat,
+) -> io::Result<()> {
+    write_u32(writer, elements.len() as u32)?;
+    for element in elements {
+        element.write(writer, format)?;
+    }
+    Ok(())
+}
+
+fn read_field_elements<R: io::Read, F: SerdePrimeField>(
+    reader: &mut R,
+    format: SerdeFormat,
+) -> io::Result<Vec<F>> {
+    let len = read_u32(reader)? as usize;
+    (0..len).map(|_| F::read(reader, format)).collect()
+}
+
+fn write_indices<W: io::Write>(writer: &mut W, indices: &[usize]) -> io::Result<()> {
+    write_u32(writer, indices.len() as u32)?;
+    for &index in indices {
+        write_u32(writer, index as u32)?;
+    }
+    Ok(())
+}
+
+fn read_indices<R: io::Read>(reader: &mut R) -> io::Result<Vec<usize>> {
+    let len = read_u32(reader)? as usize;
+    (0..len).map(|_| Ok(read_u32(reader)? as usize)).collect()
+}
+
 use super::Expression;
 
 pub fn is_pow_2(x: usize) -> bool {
@@ -34,6 +97,48 @@ pub struct StaticTable<E: MultiMillerLoop> {
     pub committed: Option<StaticCommittedTable<E>>,
 }
 
+impl<E: MultiMillerLoop> StaticTable<E>
+where
+    E::Scalar: SerdePrimeField,
+    E::G1Affine: SerdeCurveAffine,
+    E::G2Affine: SerdeCurveAffine,
+{
+    /// Persists the whole preprocessed table (quotient commitments and the
+    /// G2 commitments alike) so it can be loaded back without recomputing
+    /// `qs`, `zv`, `t` and `x_b0_bound` from scratch.
+    pub fn write<W: io::Write>(&self, writer: &mut W, format: SerdeFormat) -> io::Result<()> {
+        writer.write_all(&[self.opened.is_some() as u8])?;
+        if let Some(opened) = &self.opened {
+            opened.write(writer, format)?;
+        }
+        writer.write_all(&[self.committed.is_some() as u8])?;
+        if let Some(committed) = &self.committed {
+            committed.write(writer, format)?;
+        }
+        Ok(())
+    }
+
+    pub fn read<R: io::Read>(reader: &mut R, format: SerdeFormat) -> io::Result<Self> {
+        let mut has_opened = [0u8; 1];
+        reader.read_exact(&mut has_opened)?;
+        let opened = if has_opened[0] != 0 {
+            Some(StaticTableValues::read(reader, format)?)
+        } else {
+            None
+        };
+
+        let mut has_committed = [0u8; 1];
+        reader.read_exact(&mut has_committed)?;
+        let committed = if has_committed[0] != 0 {
+            Some(StaticCommittedTable::read(reader, format)?)
+        } else {
+            None
+        };
+
+        Ok(Self { opened, committed })
+    }
+}
+
 /// Abstract type that allows to store MAP(table_id => static_table) in proving(verifying) key
 #[derive(Copy, Clone, Debug, Eq, PartialEq, Ord, PartialOrd)]
 pub struct StaticTableId<T: Clone + Ord>(pub T);
@@ -65,11 +170,106 @@ impl<E: MultiMillerLoop> StaticTableConfig<E> {
     }
 }
 
+impl<E: MultiMillerLoop> StaticTableConfig<E>
+where
+    E::G1Affine: SerdeCurveAffine,
+{
+    pub fn write<W: io::Write>(&self, writer: &mut W, format: SerdeFormat) -> io::Result<()> {
+        write_u32(writer, self.size as u32)?;
+        write_curve_points(writer, &self.g1_lagrange, format)?;
+        write_curve_points(writer, &self.g_lagrange_opening_at_0, format)?;
+        Ok(())
+    }
+
+    pub fn read<R: io::Read>(reader: &mut R, format: SerdeFormat) -> io::Result<Self> {
+        let size = read_u32(reader)? as usize;
+        let g1_lagrange = read_curve_points(reader, format)?;
+        let g_lagrange_opening_at_0 = read_curve_points(reader, format)?;
+        Ok(Self::new(size, g1_lagrange, g_lagrange_opening_at_0))
+    }
+}
+
+/// Computes the `n` scaled quotient commitments
+/// `qs[i] = (g_i / n) * Com((T(X) - T(g_i)) / (X - g_i))`
+/// for every `g_i` in the multiplicative subgroup of order `n`, using the
+/// Feist-Khovratovich (FK) technique instead of one `kate_division` +
+/// `best_multiexp` per point. This turns the O(n^2) preprocessing into
+/// O(n log n) field and group operations.
+///
+/// `table_coeffs` is `T(X)` in monomial form (degree < n), `srs_g1[j]` is
+/// `[x^j]_1` from the KZG SRS, and `domain` is the size-`n` evaluation
+/// domain already built by the caller.
+fn fk_quotients<E: MultiMillerLoop>(
+    table_coeffs: &[E::Scalar],
+    roots_of_unity: &[E::Scalar],
+    srs_g1: &[E::G1Affine],
+    domain: &EvaluationDomain<E::Scalar>,
+    n_inv: E::Scalar,
+) -> Vec<E::G1> {
+    let n = table_coeffs.len();
+    let log_n = log2(n);
+    let double_n = 2 * n;
+    let double_domain = EvaluationDomain::<E::Scalar>::new(2, log_n + 1);
+
+    // (a) Toeplitz-to-circulant embedding of the coefficients `f_1..f_{n-1}`
+    // (f_0 never contributes to any `h_j`), padded with zeros so the
+    // length-2n cyclic convolution below doesn't wrap around.
+    let mut c = vec![E::Scalar::zero(); double_n];
+    for (j, &f_j) in table_coeffs.iter().enumerate().skip(1) {
+        c[n - j] = f_j;
+    }
+
+    // (b) the SRS group vector, padded with the identity out to length 2n.
+    let mut s = vec![E::G1::identity(); double_n];
+    for (i, &g) in srs_g1.iter().take(n - 1).enumerate() {
+        s[i] = g.into();
+    }
+
+    best_fft(&mut c, double_domain.get_omega(), log_n + 1);
+    best_fft(&mut s, double_domain.get_omega(), log_n + 1);
+
+    // (c) pointwise product of the two transforms.
+    let mut h: Vec<E::G1> = s
+        .iter()
+        .zip(c.iter())
+        .map(|(&s_i, &c_i)| s_i * c_i)
+        .collect();
+
+    // (d) inverse EC-FFT; only the first n entries (the actual Toeplitz
+    // product `h_0..h_{n-1}`) are meaningful, the rest is convolution noise.
+    best_fft(&mut h, double_domain.get_omega_inv(), log_n + 1);
+    let double_n_inv = E::Scalar::from(double_n as u64).invert().unwrap();
+    h.truncate(n);
+    for h_j in h.iter_mut() {
+        *h_j *= double_n_inv;
+    }
+    // The circulant embedding in (a)/(b) produces `h` in reverse order
+    // (`h[j] == h_{n-1-j}`); undo that before the next transform.
+    h.reverse();
+
+    // A final EC-FFT of `h` over the n-th roots of unity recovers every
+    // quotient commitment at once, which is then rescaled exactly as the
+    // naive per-point computation did: `qs[i] = (g_i * n_inv) * Com_i`.
+    best_fft(&mut h, domain.get_omega(), log_n);
+    h.iter_mut()
+        .zip(roots_of_unity.iter())
+        .for_each(|(q, &g_i)| *q *= g_i * n_inv);
+
+    h
+}
+
 #[derive(Clone, Debug)]
 pub struct StaticTableValues<E: MultiMillerLoop> {
     size: usize,
-    /// Mapping from value to its index in the table
-    value_index_mapping: BTreeMap<E::Scalar, usize>,
+    /// Number of rows the caller actually supplied, before padding `size`
+    /// up to a power of two. Equal to `size` unless the table was built
+    /// with [`StaticTableValues::new_padded`].
+    logical_size: usize,
+    /// The (possibly padded) table, in row order; kept around so `commit`
+    /// can rebuild `T(X)` without depending on `BTreeMap` iteration order.
+    values: Vec<E::Scalar>,
+    /// Mapping from value to every row index at which it occurs.
+    value_index_mapping: BTreeMap<E::Scalar, Vec<usize>>,
     // lagrange commitments will exist in params
     // quotient commitments
     qs: Vec<E::G1>,
@@ -77,13 +277,77 @@ pub struct StaticTableValues<E: MultiMillerLoop> {
 
 impl<E: MultiMillerLoop> StaticTableValues<E> {
     pub fn new(values: &[E::Scalar], srs_g1: &[E::G1Affine]) -> Self {
+        Self::from_folded_values(values, srs_g1)
+    }
+
+    /// Builds a table whose rows are tuples `(v_0, .., v_{m-1})`, one per
+    /// column in `columns`, by folding every row into the single scalar
+    /// `Σ_i alpha^i * v_i`. This lets a vector (multi-column) lookup reuse
+    /// the existing single-column cq machinery and `qs` precomputation
+    /// unchanged. `alpha` must be the same challenge the corresponding
+    /// `Argument` folds its input expressions with.
+    pub fn new_multi(columns: &[Vec<E::Scalar>], alpha: E::Scalar, srs_g1: &[E::G1Affine]) -> Self {
+        assert!(!columns.is_empty());
+        let size = columns[0].len();
+        assert!(columns.iter().all(|column| column.len() == size));
+
+        let values: Vec<E::Scalar> = (0..size)
+            .map(|row| {
+                let mut power = E::Scalar::one();
+                columns.iter().fold(E::Scalar::zero(), |acc, column| {
+                    let folded = acc + column[row] * power;
+                    power *= alpha;
+                    folded
+                })
+            })
+            .collect();
+
+        Self::from_folded_values(&values, srs_g1)
+    }
+
+    /// Builds a table from an arbitrary-length, possibly-duplicate-valued
+    /// list of rows. Unlike `new`, `size` need not be a power of two and
+    /// values need not be distinct: the logical table is padded up to the
+    /// next power of two with `filler`, and every value is mapped to *all*
+    /// of the row indices it occurs at (rather than asserting there is
+    /// exactly one), so the prover can still find a valid index for any
+    /// looked-up value.
+    pub fn new_padded(values: &[E::Scalar], filler: E::Scalar, srs_g1: &[E::G1Affine]) -> Self {
+        let logical_size = values.len();
+        let size = logical_size.next_power_of_two();
+
+        let mut padded = values.to_vec();
+        padded.resize(size, filler);
+
+        let mut value_index_mapping: BTreeMap<E::Scalar, Vec<usize>> = BTreeMap::new();
+        for (i, &v) in padded.iter().enumerate() {
+            value_index_mapping.entry(v).or_default().push(i);
+        }
+
+        Self::from_values(padded, logical_size, value_index_mapping, srs_g1)
+    }
+
+    fn from_folded_values(values: &[E::Scalar], srs_g1: &[E::G1Affine]) -> Self {
         let size = values.len();
         assert!(is_pow_2(size));
 
-        let value_index_mapping: BTreeMap<E::Scalar, usize> =
-            values.iter().enumerate().map(|(i, &f)| (f, i)).collect();
-        let keys_len: usize = value_index_mapping.keys().len();
-        assert_eq!(size, keys_len); // check that table is all unique values
+        let mut value_index_mapping: BTreeMap<E::Scalar, Vec<usize>> = BTreeMap::new();
+        for (i, &f) in values.iter().enumerate() {
+            value_index_mapping.entry(f).or_default().push(i);
+        }
+        assert_eq!(size, value_index_mapping.len()); // check that table is all unique values
+
+        Self::from_values(values.to_vec(), size, value_index_mapping, srs_g1)
+    }
+
+    fn from_values(
+        values: Vec<E::Scalar>,
+        logical_size: usize,
+        value_index_mapping: BTreeMap<E::Scalar, Vec<usize>>,
+        srs_g1: &[E::G1Affine],
+    ) -> Self {
+        let size = values.len();
+        assert!(is_pow_2(size));
 
         // compute all qs
         let domain = EvaluationDomain::<E::Scalar>::new(2, log2(size));
@@ -97,7 +361,7 @@ impl<E: MultiMillerLoop> StaticTableValues<E> {
                 .take(size)
                 .collect();
 
-        let mut table_coeffs: Vec<E::Scalar> = values.to_vec();
+        let mut table_coeffs: Vec<E::Scalar> = values.clone();
         EvaluationDomain::<E::Scalar>::ifft(
             table_coeffs.as_mut_slice(),
             domain.get_omega_inv(),
@@ -105,22 +369,12 @@ impl<E: MultiMillerLoop> StaticTableValues<E> {
             domain.ifft_divisor(),
         );
 
-        // TODO: THIS SHOULD BE DONE WITH FK METHOD
-        let qs: Vec<E::G1> = roots_of_unity
-            .iter()
-            .map(|&g_i| {
-                let quotient = kate_division(&table_coeffs, g_i);
-                let quotient = quotient
-                    .iter()
-                    .map(|&v| v * g_i * n_inv)
-                    .collect::<Vec<_>>();
-
-                best_multiexp(&quotient, &srs_g1[..quotient.len()])
-            })
-            .collect();
+        let qs = fk_quotients::<E>(&table_coeffs, &roots_of_unity, srs_g1, &domain, n_inv);
 
         Self {
             size,
+            logical_size,
+            values,
             value_index_mapping,
             qs,
         }
@@ -137,7 +391,7 @@ impl<E: MultiMillerLoop> StaticTableValues<E> {
         assert!(is_pow_2(self.size));
         let zv = srs_g2[self.size] - srs_g2[0];
 
-        let mut table_coeffs: Vec<E::Scalar> = self.value_index_mapping.keys().cloned().collect();
+        let mut table_coeffs: Vec<E::Scalar> = self.values.clone();
         EvaluationDomain::<E::Scalar>::ifft(
             table_coeffs.as_mut_slice(),
             domain.get_omega_inv(),
@@ -158,6 +412,51 @@ impl<E: MultiMillerLoop> StaticTableValues<E> {
     }
 }
 
+impl<E: MultiMillerLoop> StaticTableValues<E>
+where
+    E::Scalar: SerdePrimeField,
+    E::G1Affine: SerdeCurveAffine,
+{
+    pub fn write<W: io::Write>(&self, writer: &mut W, format: SerdeFormat) -> io::Result<()> {
+        write_u32(writer, self.size as u32)?;
+        write_u32(writer, self.logical_size as u32)?;
+        write_field_elements(writer, &self.values, format)?;
+        write_u32(writer, self.value_index_mapping.len() as u32)?;
+        for (value, indices) in self.value_index_mapping.iter() {
+            value.write(writer, format)?;
+            write_indices(writer, indices)?;
+        }
+        let qs_affine: Vec<E::G1Affine> = self.qs.iter().map(|&q| q.into()).collect();
+        write_curve_points(writer, &qs_affine, format)?;
+        Ok(())
+    }
+
+    pub fn read<R: io::Read>(reader: &mut R, format: SerdeFormat) -> io::Result<Self> {
+        let size = read_u32(reader)? as usize;
+        let logical_size = read_u32(reader)? as usize;
+        let values = read_field_elements(reader, format)?;
+        let mapping_len = read_u32(reader)? as usize;
+        let mut value_index_mapping = BTreeMap::new();
+        for _ in 0..mapping_len {
+            let value = E::Scalar::read(reader, format)?;
+            let indices = read_indices(reader)?;
+            value_index_mapping.insert(value, indices);
+        }
+        let qs = read_curve_points::<_, E::G1Affine>(reader, format)?
+            .into_iter()
+            .map(Into::into)
+            .collect();
+
+        Ok(Self {
+            size,
+            logical_size,
+            values,
+            value_index_mapping,
+            qs,
+        })
+    }
+}
+
 #[derive(Debug, Clone)]
 pub struct StaticCommittedTable<E: MultiMillerLoop> {
     pub zv: E::G2Affine,
@@ -166,22 +465,95 @@ pub struct StaticCommittedTable<E: MultiMillerLoop> {
     pub size: usize,
 }
 
+impl<E: MultiMillerLoop> StaticCommittedTable<E>
+where
+    E::G2Affine: SerdeCurveAffine,
+{
+    pub fn write<W: io::Write>(&self, writer: &mut W, format: SerdeFormat) -> io::Result<()> {
+        self.zv.write(writer, format)?;
+        self.t.write(writer, format)?;
+        self.x_b0_bound.write(writer, format)?;
+        write_u32(writer, self.size as u32)?;
+        Ok(())
+    }
+
+    pub fn read<R: io::Read>(reader: &mut R, format: SerdeFormat) -> io::Result<Self> {
+        let zv = E::G2Affine::read(reader, format)?;
+        let t = E::G2Affine::read(reader, format)?;
+        let x_b0_bound = E::G2Affine::read(reader, format)?;
+        let size = read_u32(reader)? as usize;
+
+        Ok(Self {
+            zv,
+            t,
+            x_b0_bound,
+            size,
+        })
+    }
+}
+
 #[derive(Debug, Clone)]
 pub struct Argument<F: Field> {
-    input: Expression<F>,
+    inputs: Vec<Expression<F>>,
+    /// The verifier-sampled challenge expression each input column is
+    /// folded by (`alpha^i` per column); for a single-column lookup this is
+    /// never evaluated, since `folded_input` only multiplies by `alpha` for
+    /// `i >= 1`.
+    alpha: Expression<F>,
     table_id: StaticTableId<String>,
 }
 
 impl<F: Field> Argument<F> {
-    pub fn new(name: &'static str, input: Expression<F>, table_id: StaticTableId<String>) -> Self {
-        Self { input, table_id }
+    /// `inputs` holds one expression per table column; a single-column
+    /// lookup is just `inputs.len() == 1`. Multiple columns are folded (on
+    /// both the table and the input side) into a single scalar with the
+    /// verifier-sampled challenge `alpha`, so the rest of the argument can
+    /// keep treating the lookup as single-column. `alpha` must be the same
+    /// challenge expression the corresponding `StaticTableValues::new_multi`
+    /// folded the table values with.
+    ///
+    /// This signature is a breaking change from the single-column
+    /// `new(name, input, table_id)`: every caller that builds an `Argument`
+    /// (e.g. the `ConstraintSystem::lookup_static` builder that lives
+    /// outside this module) must be updated to pass `alpha` alongside
+    /// `inputs`. This module has no such caller of its own — `table_id` is
+    /// user-facing wiring handled entirely by that builder — so the only
+    /// in-tree call sites are this module's own tests.
+    pub fn new(
+        name: &'static str,
+        inputs: Vec<Expression<F>>,
+        alpha: Expression<F>,
+        table_id: StaticTableId<String>,
+    ) -> Self {
+        assert!(
+            !inputs.is_empty(),
+            "a static lookup needs at least one input column"
+        );
+        Self {
+            inputs,
+            alpha,
+            table_id,
+        }
+    }
+
+    /// The single combined expression `Σ_i alpha^i * inputs[i]` that the
+    /// rest of the (single-column) cq machinery checks against the folded
+    /// table commitment.
+    pub(crate) fn folded_input(&self) -> Expression<F> {
+        let mut power = Expression::Constant(F::one());
+        let mut folded = Expression::Constant(F::zero());
+        for input in &self.inputs {
+            folded = folded + input.clone() * power.clone();
+            power = power * self.alpha.clone();
+        }
+        folded
     }
 
     pub(crate) fn required_degree(&self) -> usize {
         /*
             B(X)(q(X) * f(X) - \beta) - 1
         */
-        std::cmp::max(3, 2 + self.input.degree())
+        std::cmp::max(3, 2 + self.folded_input().degree())
     }
 }
 
@@ -199,3 +571,272 @@ impl<F: Field> Argument<F> {
 
 //     let _ = table.commit(params.g.len(), &params.g2_srs, 4);
 // }
+
+#[cfg(test)]
+mod tests {
+    use group::{Curve, Group};
+    use halo2curves::bn256::{Bn256, Fr, G1Affine, G2Affine};
+    use rand_core::OsRng;
+
+    use super::*;
+
+    /// A toy (non-hidden) SRS `[x^0]_1, [x^1]_1, .., [x^{len-1}]_1` good
+    /// enough to compare `fk_quotients` against the naive per-point
+    /// computation; no actual trusted setup is needed for that.
+    fn toy_srs_g1(len: usize, x: Fr) -> Vec<G1Affine> {
+        let mut power = Fr::one();
+        (0..len)
+            .map(|_| {
+                let point = (G1Affine::generator() * power).to_affine();
+                power *= x;
+                point
+            })
+            .collect()
+    }
+
+    fn toy_srs_g2(len: usize, x: Fr) -> Vec<G2Affine> {
+        let mut power = Fr::one();
+        (0..len)
+            .map(|_| {
+                let point = (G2Affine::generator() * power).to_affine();
+                power *= x;
+                point
+            })
+            .collect()
+    }
+
+    #[test]
+    fn fk_quotients_matches_naive_per_point() {
+        for log_n in [1u32, 2, 3, 4] {
+            let n = 1usize << log_n;
+            let x = Fr::random(OsRng);
+            let srs_g1 = toy_srs_g1(n, x);
+
+            let values: Vec<Fr> = (0..n).map(|_| Fr::random(OsRng)).collect();
+            let domain = EvaluationDomain::<Fr>::new(2, log_n);
+            let n_inv = Fr::from(n as u64).invert().unwrap();
+            let w = domain.get_omega();
+            let roots_of_unity: Vec<Fr> =
+                std::iter::successors(Some(Fr::one()), |p| Some(*p * w))
+                    .take(n)
+                    .collect();
+
+            let mut table_coeffs = values.clone();
+            EvaluationDomain::<Fr>::ifft(
+                table_coeffs.as_mut_slice(),
+                domain.get_omega_inv(),
+                log_n,
+                domain.ifft_divisor(),
+            );
+
+            let naive: Vec<_> = roots_of_unity
+                .iter()
+                .map(|&g_i| {
+                    let quotient = kate_division(&table_coeffs, g_i);
+                    let quotient = quotient
+                        .iter()
+                        .map(|&v| v * g_i * n_inv)
+                        .collect::<Vec<_>>();
+                    best_multiexp(&quotient, &srs_g1[..quotient.len()])
+                })
+                .collect();
+
+            let fk = fk_quotients::<Bn256>(&table_coeffs, &roots_of_unity, &srs_g1, &domain, n_inv);
+
+            for (naive_q, fk_q) in naive.iter().zip(fk.iter()) {
+                assert_eq!(naive_q.to_affine(), fk_q.to_affine());
+            }
+        }
+    }
+
+    #[test]
+    fn static_table_values_serde_roundtrip() {
+        let n = 8usize;
+        let x = Fr::random(OsRng);
+        let srs_g1 = toy_srs_g1(n, x);
+        let values: Vec<Fr> = (0..n).map(|_| Fr::random(OsRng)).collect();
+
+        let table = StaticTableValues::<Bn256>::new(&values, &srs_g1);
+
+        let mut bytes = vec![];
+        table.write(&mut bytes, SerdeFormat::RawBytesUnchecked).unwrap();
+        let read_back =
+            StaticTableValues::<Bn256>::read(&mut &bytes[..], SerdeFormat::RawBytesUnchecked)
+                .unwrap();
+
+        assert_eq!(table.size, read_back.size);
+        assert_eq!(table.logical_size, read_back.logical_size);
+        assert_eq!(table.values, read_back.values);
+        assert_eq!(table.value_index_mapping, read_back.value_index_mapping);
+        assert_eq!(
+            table.qs.iter().map(|&q| q.to_affine()).collect::<Vec<_>>(),
+            read_back
+                .qs
+                .iter()
+                .map(|&q| q.to_affine())
+                .collect::<Vec<_>>(),
+        );
+    }
+
+    #[test]
+    fn static_committed_table_serde_roundtrip() {
+        let n = 8usize;
+        let srs_g1_len = 16usize;
+        let circuit_domain = 4usize;
+        let x = Fr::random(OsRng);
+        let srs_g1 = toy_srs_g1(n, x);
+        let srs_g2 = toy_srs_g2(srs_g1_len + 4, x);
+        let values: Vec<Fr> = (0..n).map(|_| Fr::random(OsRng)).collect();
+
+        let table = StaticTableValues::<Bn256>::new(&values, &srs_g1);
+        let committed = table.commit(srs_g1_len, &srs_g2, circuit_domain);
+
+        let mut bytes = vec![];
+        committed.write(&mut bytes, SerdeFormat::RawBytesUnchecked).unwrap();
+        let read_back =
+            StaticCommittedTable::<Bn256>::read(&mut &bytes[..], SerdeFormat::RawBytesUnchecked)
+                .unwrap();
+
+        assert_eq!(committed.zv, read_back.zv);
+        assert_eq!(committed.t, read_back.t);
+        assert_eq!(committed.x_b0_bound, read_back.x_b0_bound);
+        assert_eq!(committed.size, read_back.size);
+    }
+
+    #[test]
+    fn static_table_config_serde_roundtrip() {
+        let x = Fr::random(OsRng);
+        let config = StaticTableConfig::<Bn256>::new(4, toy_srs_g1(4, x), toy_srs_g1(4, x));
+
+        let mut bytes = vec![];
+        config.write(&mut bytes, SerdeFormat::RawBytesUnchecked).unwrap();
+        let read_back =
+            StaticTableConfig::<Bn256>::read(&mut &bytes[..], SerdeFormat::RawBytesUnchecked)
+                .unwrap();
+
+        let mut original = vec![];
+        config.write(&mut original, SerdeFormat::RawBytesUnchecked).unwrap();
+        let mut roundtripped = vec![];
+        read_back
+            .write(&mut roundtripped, SerdeFormat::RawBytesUnchecked)
+            .unwrap();
+        assert_eq!(original, roundtripped);
+    }
+
+    #[test]
+    fn static_table_serde_roundtrip() {
+        let n = 8usize;
+        let srs_g1_len = 16usize;
+        let circuit_domain = 4usize;
+        let x = Fr::random(OsRng);
+        let srs_g1 = toy_srs_g1(n, x);
+        let srs_g2 = toy_srs_g2(srs_g1_len + 4, x);
+        let values: Vec<Fr> = (0..n).map(|_| Fr::random(OsRng)).collect();
+
+        let opened = StaticTableValues::<Bn256>::new(&values, &srs_g1);
+        let committed = opened.commit(srs_g1_len, &srs_g2, circuit_domain);
+        let table = StaticTable {
+            opened: Some(opened),
+            committed: Some(committed),
+        };
+
+        let mut bytes = vec![];
+        table.write(&mut bytes, SerdeFormat::RawBytesUnchecked).unwrap();
+        let read_back =
+            StaticTable::<Bn256>::read(&mut &bytes[..], SerdeFormat::RawBytesUnchecked).unwrap();
+
+        assert!(read_back.opened.is_some());
+        assert!(read_back.committed.is_some());
+        assert_eq!(
+            table.committed.as_ref().unwrap().t,
+            read_back.committed.as_ref().unwrap().t
+        );
+    }
+
+    #[test]
+    fn new_multi_folds_columns_like_new() {
+        let alpha = Fr::from(7);
+        let column_a = vec![Fr::from(1), Fr::from(2), Fr::from(3), Fr::from(4)];
+        let column_b = vec![Fr::from(5), Fr::from(6), Fr::from(7), Fr::from(8)];
+
+        let expected_values: Vec<Fr> = column_a
+            .iter()
+            .zip(column_b.iter())
+            .map(|(&a, &b)| a + b * alpha)
+            .collect();
+
+        let x = Fr::random(OsRng);
+        let srs_g1 = toy_srs_g1(4, x);
+        let multi = StaticTableValues::<Bn256>::new_multi(&[column_a, column_b], alpha, &srs_g1);
+        let single = StaticTableValues::<Bn256>::new(&expected_values, &srs_g1);
+
+        assert_eq!(multi.values, single.values);
+        assert_eq!(multi.value_index_mapping, single.value_index_mapping);
+        assert_eq!(
+            multi.qs.iter().map(|&q| q.to_affine()).collect::<Vec<_>>(),
+            single.qs.iter().map(|&q| q.to_affine()).collect::<Vec<_>>(),
+        );
+    }
+
+    #[test]
+    fn argument_required_degree_with_folded_inputs() {
+        let alpha = Expression::Constant(Fr::from(7));
+        let single = Argument::new(
+            "single_column",
+            vec![Expression::Constant(Fr::from(1))],
+            alpha.clone(),
+            StaticTableId("t".to_string()),
+        );
+        assert_eq!(single.required_degree(), 3); // max(3, 2 + 0)
+
+        let multi = Argument::new(
+            "multi_column",
+            vec![
+                Expression::Constant(Fr::from(1)),
+                Expression::Constant(Fr::from(2)),
+                Expression::Constant(Fr::from(3)),
+            ],
+            alpha,
+            StaticTableId("t".to_string()),
+        );
+        // folding with a degree-0 challenge doesn't raise the degree bound
+        // above that of a single column.
+        assert_eq!(multi.required_degree(), 3);
+    }
+
+    #[test]
+    fn new_padded_allows_duplicates_and_pads_to_pow2() {
+        // 5 logical rows, with `Fr::from(2)` appearing twice; pads to size 8.
+        let values = vec![
+            Fr::from(1),
+            Fr::from(2),
+            Fr::from(3),
+            Fr::from(2),
+            Fr::from(4),
+        ];
+        let filler = Fr::from(0);
+        let x = Fr::random(OsRng);
+        let srs_g1 = toy_srs_g1(8, x);
+
+        let table = StaticTableValues::<Bn256>::new_padded(&values, filler, &srs_g1);
+
+        assert_eq!(table.logical_size, 5);
+        assert_eq!(table.size, 8);
+        assert_eq!(table.values.len(), 8);
+        assert_eq!(&table.values[..5], &values[..]);
+        assert_eq!(&table.values[5..], &[filler, filler, filler]);
+
+        // the duplicated value maps to every index it occurs at...
+        assert_eq!(
+            table.value_index_mapping.get(&Fr::from(2)),
+            Some(&vec![1usize, 3])
+        );
+        // ...and so does the filler, once per padded slot.
+        assert_eq!(
+            table.value_index_mapping.get(&filler),
+            Some(&vec![5usize, 6, 7])
+        );
+        // every other value still maps to its single occurrence.
+        assert_eq!(table.value_index_mapping.get(&Fr::from(3)), Some(&vec![2usize]));
+    }
+}
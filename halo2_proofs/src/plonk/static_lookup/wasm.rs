@@ -0,0 +1,195 @@
+//! Curve- and circuit-generic prove/verify entry points, usable from a
+//! `wasm32-unknown-unknown` build, that keep `OsRng`/`getrandom` and
+//! filesystem access out of the hot path.
+//!
+//! `prove` and `verify` stay generic over `E`/`C`/`R` so this module has no
+//! dependency on `wasm-bindgen` itself (generic functions can't be exposed
+//! through it anyway); a concrete embedder monomorphizes these for one
+//! curve and wraps them with `#[wasm_bindgen]`. The only thing deserialized
+//! here is the KZG parameters (see [`ParamsKZG::write`]) — `pk`/`vk` (and
+//! whatever `StaticTable` the circuit holds) are still built the normal way
+//! via `keygen_pk`/`keygen_vk`, the same as any non-wasm caller. Neither
+//! `prove` nor `verify` falls back to `OsRng`/`getrandom` or touches the
+//! filesystem, so that part of the path builds and runs in the browser.
+
+use std::io;
+
+use halo2curves::pairing::{MillerLoopResult, MultiMillerLoop};
+use rand_core::RngCore;
+
+use crate::{
+    plonk::{create_proof, verify_proof, Circuit, ProvingKey, VerifyingKey},
+    poly::{
+        kzg::{
+            commitment::ParamsKZG,
+            multiopen::{ProverGWC, VerifierGWC},
+            strategy::AccumulatorStrategy,
+        },
+        VerificationStrategy,
+    },
+    transcript::{
+        Blake2bRead, Blake2bWrite, Challenge255, TranscriptReadBuffer, TranscriptWriterBuffer,
+    },
+    SerdeFormat,
+};
+
+/// Deserializes a previously-generated `ParamsKZG` and builds a cq proof
+/// for `circuits` using an already-built `pk` (including whatever
+/// `StaticTable` its circuit was keyed with). The caller supplies the
+/// randomness (`rng`) rather than this function reaching for `OsRng`,
+/// which pulls in `getrandom` and has no implementation on
+/// `wasm32-unknown-unknown` without extra host glue.
+pub fn prove<E, C, R>(
+    params_bytes: &[u8],
+    pk: &ProvingKey<E>,
+    circuits: &[C],
+    rng: R,
+    format: SerdeFormat,
+) -> io::Result<Vec<u8>>
+where
+    E: MultiMillerLoop,
+    C: Circuit<E>,
+    R: RngCore,
+{
+    let params = ParamsKZG::<E>::read(&mut io::Cursor::new(params_bytes), format)?;
+
+    let mut transcript = Blake2bWrite::<_, _, Challenge255<_>>::init(vec![]);
+    create_proof::<E, ProverGWC<_>, _, _, _, _>(&params, pk, circuits, &[], rng, &mut transcript)
+        .map_err(|e| io::Error::new(io::ErrorKind::Other, e.to_string()))?;
+
+    Ok(transcript.finalize())
+}
+
+/// Deserializes a previously-generated `ParamsKZG` and checks `proof`
+/// against `vk`, including the `StaticCommittedTable` pairing checks for
+/// every static lookup the circuit uses. Returns `true` iff the proof and
+/// every static table pairing check are valid; no randomness is needed.
+pub fn verify<E>(
+    params_bytes: &[u8],
+    vk: &VerifyingKey<E>,
+    proof: &[u8],
+    format: SerdeFormat,
+) -> io::Result<bool>
+where
+    E: MultiMillerLoop,
+{
+    let params = ParamsKZG::<E>::read(&mut io::Cursor::new(params_bytes), format)?;
+    let verifier_params = params.verifier_params();
+    let strategy = VerificationStrategy::<E, VerifierGWC<_>>::new(verifier_params);
+    let mut transcript = Blake2bRead::<_, _, Challenge255<_>>::init(proof);
+
+    let batcher = verify_proof::<E, VerifierGWC<_>, _, _, AccumulatorStrategy<_>>(
+        verifier_params,
+        vk,
+        strategy,
+        &[],
+        &mut transcript,
+    )
+    .map_err(|e| io::Error::new(io::ErrorKind::Other, e.to_string()))?;
+
+    let batched_tuples = batcher.finalize();
+    let result = E::multi_miller_loop(
+        &batched_tuples
+            .iter()
+            .map(|(g1, g2)| (g1, g2))
+            .collect::<Vec<_>>(),
+    );
+
+    Ok(bool::from(result.final_exponentiation().is_identity()))
+}
+
+#[cfg(test)]
+mod tests {
+    use ff::Field;
+    use group::prime::PrimeCurveAffine;
+    use halo2curves::bn256::{Bn256, Fr};
+    use rand_chacha::ChaCha8Rng;
+    use rand_core::{OsRng, SeedableRng};
+
+    use super::*;
+    use crate::{
+        circuit::SimpleFloorPlanner,
+        plonk::{keygen_pk, keygen_vk, static_lookup::StaticTableId, Advice, Column, ConstraintSystem},
+        poly::{commitment::ParamsProver, Rotation},
+    };
+
+    #[derive(Clone)]
+    struct LookupCircuit<E: MultiMillerLoop> {
+        table: super::super::StaticTable<E>,
+    }
+
+    impl<E: MultiMillerLoop<Scalar = F>, F: Field> Circuit<E> for LookupCircuit<E> {
+        type Config = Column<Advice>;
+        type FloorPlanner = SimpleFloorPlanner<E>;
+
+        fn without_witnesses(&self) -> Self {
+            self.clone()
+        }
+
+        fn configure(meta: &mut ConstraintSystem<F>) -> Self::Config {
+            let advice = meta.advice_column();
+            meta.lookup_static("wasm_roundtrip", |meta| {
+                (
+                    meta.query_advice(advice, Rotation::cur()),
+                    StaticTableId(String::from("wasm_table")),
+                )
+            });
+            advice
+        }
+
+        fn synthesize(
+            &self,
+            config: Self::Config,
+            mut layouter: impl crate::circuit::Layouter<F, E = E>,
+        ) -> Result<(), crate::plonk::Error> {
+            layouter.register_static_table(StaticTableId(String::from("wasm_table")), self.table.clone());
+            Ok(())
+        }
+    }
+
+    // Mirrors `halo2_proofs/tests/my_test.rs`: builds params/pk/vk for a
+    // single-column static lookup, then checks that `prove` followed by
+    // `verify` accepts the resulting proof.
+    #[test]
+    fn prove_verify_roundtrip() {
+        const K: u32 = 6;
+        let mut rng = ChaCha8Rng::seed_from_u64(0xc9);
+
+        let opened = super::super::StaticTableValues::<Bn256> { x: Fr::from(5) };
+        let committed = opened.commit(<Bn256 as halo2curves::pairing::Engine>::G2Affine::generator());
+        let table = super::super::StaticTable {
+            opened: Some(opened),
+            committed: Some(committed),
+        };
+
+        let params = ParamsKZG::<Bn256>::new(K, &mut rng);
+        let circuit = LookupCircuit { table };
+
+        let vk = keygen_vk(&params, &circuit).expect("keygen_vk should not fail");
+        let pk = keygen_pk(&params, vk, &circuit).expect("keygen_pk should not fail");
+
+        let mut params_bytes = vec![];
+        params
+            .write(&mut params_bytes)
+            .expect("params should serialize");
+
+        let proof = prove(
+            &params_bytes,
+            &pk,
+            &[circuit],
+            OsRng,
+            SerdeFormat::RawBytesUnchecked,
+        )
+        .expect("prove should not fail");
+
+        let verified = verify(
+            &params_bytes,
+            pk.get_vk(),
+            &proof,
+            SerdeFormat::RawBytesUnchecked,
+        )
+        .expect("verify should not fail");
+
+        assert!(verified);
+    }
+}